@@ -0,0 +1,345 @@
+//! Parser for ABC notation, a compact text format for monophonic
+//! melodies, so tunes from the large body of existing ABC transcriptions
+//! can be played straight from the menu.
+
+use std::io;
+
+use crate::Note;
+
+/// Parse an ABC-notation tune into a sequence of playable [`Note`]s.
+///
+/// Only the `L:` (default note length), `Q:` (tempo) and `K:` (key)
+/// header fields are recognised; everything else in the header is
+/// skipped. The tune is treated as monophonic: chords written as
+/// `[CEG]` contribute only their highest note. The key signature's
+/// implicit sharps/flats apply to every unmarked note of that letter;
+/// an explicit `^`/`_`/`=` on a note always overrides it. Malformed
+/// tokens are skipped with a warning rather than causing a panic.
+pub fn parse_abc(data: &str) -> io::Result<Vec<Note>> {
+    let mut default_length = 1.0 / 8.0;
+    let mut bpm: u32 = 120;
+    let mut key_accidentals = [0i32; 7];
+    let mut notes = Vec::new();
+
+    for line in data.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('%') {
+            continue;
+        }
+
+        if is_header_line(line) {
+            let (key, value) = line.split_once(':').unwrap();
+            let value = value.trim();
+            match key.trim() {
+                "L" => match parse_fraction(value) {
+                    Some(f) => default_length = f,
+                    None => println!("Warning: Invalid ABC note length: {}", value),
+                },
+                "Q" => match parse_tempo(value) {
+                    Some(q) => bpm = q,
+                    None => println!("Warning: Invalid ABC tempo: {}", value),
+                },
+                "K" => key_accidentals = parse_key_signature(value),
+                _ => {}
+            }
+            continue;
+        }
+
+        parse_body_line(line, default_length, bpm, &key_accidentals, &mut notes);
+    }
+
+    Ok(notes)
+}
+
+fn is_header_line(line: &str) -> bool {
+    let mut chars = line.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic()) && chars.next() == Some(':')
+}
+
+/// Parse a fraction like `1/8` or a bare integer into a float.
+fn parse_fraction(value: &str) -> Option<f64> {
+    match value.split_once('/') {
+        Some((num, den)) => {
+            let num: f64 = num.parse().ok()?;
+            let den: f64 = den.parse().ok()?;
+            if den == 0.0 {
+                None
+            } else {
+                Some(num / den)
+            }
+        }
+        None => value.parse().ok(),
+    }
+}
+
+/// Parse a `Q:` tempo field. Accepts a bare bpm (`120`) or the
+/// `note_length=bpm` form (`1/4=120`), taking the bpm half.
+fn parse_tempo(value: &str) -> Option<u32> {
+    let bpm_part = value.rsplit('=').next().unwrap_or(value);
+    bpm_part.trim().parse().ok()
+}
+
+/// Number of sharps (positive) or flats (negative) in each key's
+/// signature, keyed by its tonic. Major keys first, then their
+/// relative minors; anything unrecognised (including modes we don't
+/// special-case, e.g. `Ddor`) falls back to no accidentals.
+const MAJOR_KEY_SHARPS: [(&str, i32); 15] = [
+    ("C", 0),
+    ("G", 1), ("D", 2), ("A", 3), ("E", 4), ("B", 5), ("F#", 6), ("C#", 7),
+    ("F", -1), ("Bb", -2), ("Eb", -3), ("Ab", -4), ("Db", -5), ("Gb", -6), ("Cb", -7),
+];
+const MINOR_KEY_SHARPS: [(&str, i32); 15] = [
+    ("A", 0),
+    ("E", 1), ("B", 2), ("F#", 3), ("C#", 4), ("G#", 5), ("D#", 6), ("A#", 7),
+    ("D", -1), ("G", -2), ("C", -3), ("F", -4), ("Bb", -5), ("Eb", -6), ("Ab", -7),
+];
+
+/// The order sharps/flats are added to a key signature in, by letter.
+const SHARP_ORDER: [char; 7] = ['f', 'c', 'g', 'd', 'a', 'e', 'b'];
+const FLAT_ORDER: [char; 7] = ['b', 'e', 'a', 'd', 'g', 'c', 'f'];
+
+/// Parse a `K:` key field (e.g. `D`, `Bb`, `Dm`, `Amaj`, `F#m`) into the
+/// per-letter accidental it implies, indexed by `letter - 'a'`.
+fn parse_key_signature(value: &str) -> [i32; 7] {
+    let token = value.split_whitespace().next().unwrap_or("C");
+    let (tonic, minor) = match token.strip_suffix('m').or_else(|| token.strip_suffix("min")) {
+        Some(tonic) if !tonic.is_empty() => (tonic, true),
+        _ => (token.strip_suffix("maj").unwrap_or(token), false),
+    };
+
+    let table = if minor { &MINOR_KEY_SHARPS } else { &MAJOR_KEY_SHARPS };
+    let count = table
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(tonic))
+        .map(|(_, count)| *count)
+        .unwrap_or(0);
+
+    let mut accidentals = [0i32; 7];
+    if count > 0 {
+        for &letter in SHARP_ORDER.iter().take(count as usize) {
+            accidentals[(letter as u8 - b'a') as usize] = 1;
+        }
+    } else if count < 0 {
+        for &letter in FLAT_ORDER.iter().take((-count) as usize) {
+            accidentals[(letter as u8 - b'a') as usize] = -1;
+        }
+    }
+    accidentals
+}
+
+/// Parse one line of the tune body, pushing resulting notes/rests.
+fn parse_body_line(
+    line: &str,
+    default_length: f64,
+    bpm: u32,
+    key_accidentals: &[i32; 7],
+    notes: &mut Vec<Note>,
+) {
+    let quarter_ms = 60_000.0 / bpm.max(1) as f64;
+    let whole_ms = quarter_ms * 4.0;
+
+    let chars: Vec<char> = line.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            ' ' | '\t' | '|' | ':' => {
+                i += 1;
+            }
+            '"' => {
+                // Skip an inline chord symbol like "Cmaj7".
+                i += 1;
+                while i < chars.len() && chars[i] != '"' {
+                    i += 1;
+                }
+                i += 1; // consume closing quote, if any
+            }
+            '[' => {
+                let close = match chars[i..].iter().position(|&c| c == ']') {
+                    Some(offset) => i + offset,
+                    None => {
+                        println!("Warning: Unterminated ABC chord: {}", line);
+                        return;
+                    }
+                };
+                let pitch = top_chord_pitch(&chars[i + 1..close], key_accidentals);
+                i = close + 1;
+                let (length_factor, advance) = parse_length_modifier(&chars, i);
+                i += advance;
+                let duration_ms = (default_length * length_factor * whole_ms).round() as u32;
+                match pitch {
+                    Some(semitone_octave) => {
+                        let (semitone, octave) = semitone_octave;
+                        notes.push(Note::new(crate::note_to_freq(semitone, octave), duration_ms));
+                    }
+                    None => println!("Warning: Empty ABC chord: {}", line),
+                }
+            }
+            'z' | 'Z' => {
+                i += 1;
+                let (length_factor, advance) = parse_length_modifier(&chars, i);
+                i += advance;
+                let duration_ms = (default_length * length_factor * whole_ms).round() as u32;
+                notes.push(Note::new(0, duration_ms));
+            }
+            '^' | '_' | '=' => {
+                // Accidental: parse it together with the note that follows.
+                match parse_note(&chars, i, key_accidentals) {
+                    Some((semitone, octave, advance)) => {
+                        i += advance;
+                        let (length_factor, len_advance) = parse_length_modifier(&chars, i);
+                        i += len_advance;
+                        let duration_ms = (default_length * length_factor * whole_ms).round() as u32;
+                        notes.push(Note::new(crate::note_to_freq(semitone, octave), duration_ms));
+                    }
+                    None => {
+                        println!("Warning: Invalid ABC token near: {}", line);
+                        i += 1;
+                    }
+                }
+            }
+            c if c.is_ascii_alphabetic() => {
+                match parse_note(&chars, i, key_accidentals) {
+                    Some((semitone, octave, advance)) => {
+                        i += advance;
+                        let (length_factor, len_advance) = parse_length_modifier(&chars, i);
+                        i += len_advance;
+                        let duration_ms = (default_length * length_factor * whole_ms).round() as u32;
+                        notes.push(Note::new(crate::note_to_freq(semitone, octave), duration_ms));
+                    }
+                    None => {
+                        println!("Warning: Unknown ABC note: {}", c);
+                        i += 1;
+                    }
+                }
+            }
+            _ => {
+                i += 1;
+            }
+        }
+    }
+}
+
+/// Parse a single pitch starting at `chars[i]`, which may be preceded
+/// by an accidental (`^`, `_`, `=`) and followed by octave markers
+/// (`'` or `,`). Returns `(semitone, octave, chars_consumed)`.
+///
+/// An explicit accidental always wins; otherwise the note falls back
+/// to whatever `key_accidentals` (from the tune's `K:` field) says for
+/// its letter.
+fn parse_note(chars: &[char], i: usize, key_accidentals: &[i32; 7]) -> Option<(i32, i32, usize)> {
+    let mut pos = i;
+    let mut explicit_accidental: Option<i32> = None;
+    match chars.get(pos) {
+        Some('^') => {
+            explicit_accidental = Some(1);
+            pos += 1;
+        }
+        Some('_') => {
+            explicit_accidental = Some(-1);
+            pos += 1;
+        }
+        Some('=') => {
+            explicit_accidental = Some(0);
+            pos += 1;
+        }
+        _ => {}
+    }
+
+    let letter = *chars.get(pos)?;
+    pos += 1;
+
+    let (base_semitone, mut octave) = match letter {
+        'A'..='G' => (letter_to_semitone(letter.to_ascii_lowercase()), 4),
+        'a'..='g' => (letter_to_semitone(letter), 5),
+        _ => return None,
+    };
+
+    while let Some(&c) = chars.get(pos) {
+        match c {
+            '\'' => {
+                octave += 1;
+                pos += 1;
+            }
+            ',' => {
+                octave -= 1;
+                pos += 1;
+            }
+            _ => break,
+        }
+    }
+
+    let key_accidental = key_accidentals[(letter.to_ascii_lowercase() as u8 - b'a') as usize];
+    let accidental = explicit_accidental.unwrap_or(key_accidental);
+
+    Some((base_semitone + accidental, octave, pos - i))
+}
+
+/// Parse an optional `[multiplier][/[divisor]]` length modifier
+/// starting at `chars[i]`. Returns `(factor, chars_consumed)`.
+fn parse_length_modifier(chars: &[char], i: usize) -> (f64, usize) {
+    let mut pos = i;
+    let mut factor = 1.0;
+
+    let digit_start = pos;
+    while matches!(chars.get(pos), Some(c) if c.is_ascii_digit()) {
+        pos += 1;
+    }
+    if pos > digit_start {
+        if let Ok(n) = chars[digit_start..pos].iter().collect::<String>().parse::<f64>() {
+            factor *= n;
+        }
+    }
+
+    if chars.get(pos) == Some(&'/') {
+        pos += 1;
+        let div_start = pos;
+        while matches!(chars.get(pos), Some(c) if c.is_ascii_digit()) {
+            pos += 1;
+        }
+        let divisor: f64 = if pos > div_start {
+            chars[div_start..pos].iter().collect::<String>().parse().unwrap_or(2.0)
+        } else {
+            2.0
+        };
+        if divisor != 0.0 {
+            factor /= divisor;
+        }
+    }
+
+    (factor, pos - i)
+}
+
+fn letter_to_semitone(letter: char) -> i32 {
+    match letter {
+        'c' => 0,
+        'd' => 2,
+        'e' => 4,
+        'f' => 5,
+        'g' => 7,
+        'a' => 9,
+        'b' => 11,
+        _ => unreachable!("caller only passes a-g"),
+    }
+}
+
+/// Pick the highest-pitched note within a chord's contents (the
+/// characters between `[` and `]`), since the PC speaker is monophonic.
+fn top_chord_pitch(contents: &[char], key_accidentals: &[i32; 7]) -> Option<(i32, i32)> {
+    let mut best: Option<(i32, i32)> = None;
+    let mut i = 0;
+    while i < contents.len() {
+        match parse_note(contents, i, key_accidentals) {
+            Some((semitone, octave, advance)) => {
+                i += advance.max(1);
+                let midi = (octave + 1) * 12 + semitone;
+                let best_midi = best.map(|(s, o)| (o + 1) * 12 + s);
+                if best_midi.is_none_or(|bm| midi > bm) {
+                    best = Some((semitone, octave));
+                }
+            }
+            None => i += 1,
+        }
+    }
+    best
+}
@@ -0,0 +1,48 @@
+//! Chord support for custom melody files.
+//!
+//! The PC speaker can only sound one frequency at a time, so a chord is
+//! flattened into a quick strum: every note but the last rings briefly
+//! as a grace note, and the top note is held for the rest of the
+//! chord's duration.
+
+use crate::Note;
+
+/// How long each grace note in a strummed chord is held, in ms.
+pub(crate) const DEFAULT_STRUM_MS: u32 = 30;
+
+/// The largest strum speed a melody file is allowed to request.
+pub(crate) const MAX_STRUM_MS: u32 = 1000;
+
+/// A set of frequencies meant to sound together, which the hardware
+/// can only approximate by arpeggiating.
+pub(crate) struct Chord {
+    pub(crate) notes: Vec<u32>,
+    pub(crate) duration_ms: u32,
+}
+
+/// Expand a chord into the sequence of notes that approximates it on
+/// monophonic hardware: a fast strum through every note but the last,
+/// then the top (highest-pitched) note held for the remainder. The
+/// strum never takes more than `chord.duration_ms` in total, however
+/// large `strum_ms` is - grace notes are scaled down to fit.
+pub(crate) fn arpeggiate(chord: Chord, strum_ms: u32) -> Vec<Note> {
+    let mut frequencies = chord.notes;
+    if frequencies.is_empty() {
+        return Vec::new();
+    }
+    frequencies.sort_unstable();
+
+    let top = frequencies.pop().unwrap();
+    let grace_count = frequencies.len() as u32;
+    let strum_total = strum_ms.saturating_mul(grace_count).min(chord.duration_ms);
+    let hold_ms = chord.duration_ms - strum_total;
+    let grace_ms = if grace_count == 0 { 0 } else { (strum_total / grace_count).max(1) };
+
+    let mut notes: Vec<Note> = frequencies
+        .into_iter()
+        .map(|freq| Note::new(freq, grace_ms))
+        .collect();
+    notes.push(Note::new(top, hold_ms.max(1)));
+    notes
+}
+
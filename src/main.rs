@@ -1,13 +1,18 @@
 use libc::{c_int, c_uint, ioctl};
 use nix::unistd::Uid;
-use std::fs::{File, OpenOptions};
+use std::fs::{self, File};
 use std::io::{self, Write, BufRead, BufReader};
 use std::os::fd::AsRawFd;
-use std::thread::sleep;
-use std::time::Duration;
 use std::path::Path;
 use std::collections::HashMap;
 
+mod abc;
+mod chord;
+mod player;
+mod playlist;
+mod rtttl;
+mod tempo;
+
 // Constants for ioctl calls to PC speaker
 const CONSOLE_IOCTL: u8 = 0x09;
 const KDMKTONE: u64 = 0x4B30;
@@ -18,7 +23,6 @@ const HZ: u32 = 1193180;
 const NOTE_C4: u32 = 262;
 const NOTE_CS4: u32 = 277;
 const NOTE_D4: u32 = 294;
-const NOTE_DS4: u32 = 311;
 const NOTE_E4: u32 = 330;
 const NOTE_F4: u32 = 349;
 const NOTE_FS4: u32 = 370;
@@ -32,29 +36,10 @@ const NOTE_B4: u32 = 494;
 const NOTE_C5: u32 = 523;
 const NOTE_CS5: u32 = 554;
 const NOTE_D5: u32 = 587;
-const NOTE_DS5: u32 = 622;
 const NOTE_E5: u32 = 659;
 const NOTE_F5: u32 = 698;
-const NOTE_FS5: u32 = 740;
 const NOTE_G5: u32 = 784;
-const NOTE_GS5: u32 = 831;
 const NOTE_A5: u32 = 880;
-const NOTE_AS5: u32 = 932;
-const NOTE_B5: u32 = 988;
-
-// Lower octave
-const NOTE_C3: u32 = 131;
-const NOTE_CS3: u32 = 139;
-const NOTE_D3: u32 = 147;
-const NOTE_DS3: u32 = 156;
-const NOTE_E3: u32 = 165;
-const NOTE_F3: u32 = 175;
-const NOTE_FS3: u32 = 185;
-const NOTE_G3: u32 = 196;
-const NOTE_GS3: u32 = 208;
-const NOTE_A3: u32 = 220;
-const NOTE_AS3: u32 = 233;
-const NOTE_B3: u32 = 247;
 
 const NOTE_REST: u32 = 0;
 
@@ -67,13 +52,13 @@ const SIXTEENTH: u32 = 63;
 const NOKIA_SIXTEENTH: u32 = 95; // Nokia tune timing
 
 /// A music note with its frequency and duration
-struct Note {
-    frequency: u32,
-    duration_ms: u32,
+pub(crate) struct Note {
+    pub(crate) frequency: u32,
+    pub(crate) duration_ms: u32,
 }
 
 impl Note {
-    fn new(frequency: u32, duration_ms: u32) -> Self {
+    pub(crate) fn new(frequency: u32, duration_ms: u32) -> Self {
         Self {
             frequency,
             duration_ms,
@@ -81,8 +66,25 @@ impl Note {
     }
 }
 
-/// Generate a beep using kernel syscall (like motherboard beeps)
-fn kernel_beep(frequency: u32, duration_ms: u32) -> io::Result<()> {
+/// The names of the twelve semitones within an octave, starting at C,
+/// in the order consumed by [`note_to_freq`].
+pub(crate) const SEMITONE_NAMES: [&str; 12] = [
+    "C", "CS", "D", "DS", "E", "F", "FS", "G", "GS", "A", "AS", "B",
+];
+
+/// Compute the equal-temperament frequency (rounded to the nearest Hz)
+/// of a note `semitone` steps above C (0-11) in the given `octave`,
+/// using the standard A4 = 440 Hz tuning reference.
+pub(crate) fn note_to_freq(semitone: i32, octave: i32) -> u32 {
+    let midi = (octave + 1) * 12 + semitone;
+    let freq = 440.0 * 2f64.powf((midi - 69) as f64 / 12.0);
+    freq.round() as u32
+}
+
+/// Trigger the PC speaker via the console ioctl, falling back to the
+/// terminal bell if there's no open console or the ioctl call fails.
+/// Does not sleep - the caller (the player thread) owns timing.
+pub(crate) fn beep_on_console(console: Option<&File>, frequency: u32, duration_ms: u32) -> io::Result<()> {
     // Convert frequency to PC speaker value
     let arg = if frequency == 0 {
         // Silence
@@ -93,36 +95,32 @@ fn kernel_beep(frequency: u32, duration_ms: u32) -> io::Result<()> {
         freq_val | (duration_ms << 16)
     };
 
-    // Attempt to open the console
-    match OpenOptions::new().read(true).write(true).open("/dev/console") {
-        Ok(console) => {
+    match console {
+        Some(console) => {
             // Make the ioctl call to generate beep
-            let result = unsafe { 
+            let result = unsafe {
                 // Convert u64 to c_uint using into()
-                ioctl(console.as_raw_fd(), (KDMKTONE as c_uint).into(), arg as c_int) 
+                ioctl(console.as_raw_fd(), (KDMKTONE as c_uint).into(), arg as c_int)
             };
-            
+
             if result != 0 {
                 // Fall back to system bell if ioctl fails
                 print!("\x07"); // ASCII bell character
                 io::stdout().flush()?;
             }
         }
-        Err(err) => {
-            eprintln!("Could not open console: {}", err);
-            // Try with simple print of bell character
+        None => {
+            // No console handle available - fall back to the terminal bell.
             print!("\x07"); // ASCII bell character
             io::stdout().flush()?;
         }
     }
 
-    // Always sleep for the duration
-    sleep(Duration::from_millis(duration_ms as u64));
     Ok(())
 }
 
 /// Play the Tetris theme (Korobeiniki)
-fn play_tetris_theme() -> io::Result<()> {
+fn play_tetris_theme(player: &player::Player) -> io::Result<()> {
     println!("Playing Tetris Theme...");
 
     let notes = [
@@ -171,17 +169,13 @@ fn play_tetris_theme() -> io::Result<()> {
         Note::new(NOTE_REST, QUARTER),
     ];
 
-    for note in notes.iter() {
-        kernel_beep(note.frequency, note.duration_ms)?;
-        // Small break between notes
-        sleep(Duration::from_millis(10));
-    }
+    player.play(Vec::from(notes), 10);
 
     Ok(())
 }
 
 /// Play Jingle Bells melody
-fn play_jingle_bells() -> io::Result<()> {
+fn play_jingle_bells(player: &player::Player) -> io::Result<()> {
     println!("Playing Jingle Bells...");
 
     let notes = [
@@ -204,17 +198,13 @@ fn play_jingle_bells() -> io::Result<()> {
         Note::new(NOTE_E4, WHOLE),
     ];
 
-    for note in notes.iter() {
-        kernel_beep(note.frequency, note.duration_ms)?;
-        // Small break between notes
-        sleep(Duration::from_millis(50));
-    }
+    player.play(Vec::from(notes), 50);
 
     Ok(())
 }
 
 /// Play the Imperial March from Star Wars
-fn play_imperial_march() -> io::Result<()> {
+fn play_imperial_march(player: &player::Player) -> io::Result<()> {
     println!("Playing Imperial March...");
 
     let notes = [
@@ -229,17 +219,13 @@ fn play_imperial_march() -> io::Result<()> {
         Note::new(NOTE_G4, QUARTER), Note::new((NOTE_E4 * 3) / 4, QUARTER), Note::new(NOTE_B4, EIGHTH), Note::new(NOTE_G4, HALF),
     ];
 
-    for note in notes.iter() {
-        kernel_beep(note.frequency, note.duration_ms)?;
-        // Small break between notes
-        sleep(Duration::from_millis(50));
-    }
+    player.play(Vec::from(notes), 50);
 
     Ok(())
 }
 
 /// Play the classic Nokia ringtone
-fn play_nokia_tune() -> io::Result<()> {
+fn play_nokia_tune(player: &player::Player) -> io::Result<()> {
     println!("Playing Nokia Tune (Gran Vals)...");
 
     let notes = [
@@ -252,17 +238,13 @@ fn play_nokia_tune() -> io::Result<()> {
         Note::new(NOTE_A4, NOKIA_SIXTEENTH * 4),
     ];
 
-    for note in notes.iter() {
-        kernel_beep(note.frequency, note.duration_ms)?;
-        // Small break between notes for clarity
-        sleep(Duration::from_millis(30));
-    }
+    player.play(Vec::from(notes), 30);
 
     Ok(())
 }
 
 /// Play Super Mario Bros theme
-fn play_super_mario() -> io::Result<()> {
+fn play_super_mario(player: &player::Player) -> io::Result<()> {
     println!("Playing Super Mario Bros theme...");
 
     let tempo = 1.2; // Speed factor (higher = faster)
@@ -312,25 +294,15 @@ fn play_super_mario() -> io::Result<()> {
         Note::new(NOTE_REST, quarter),
     ];
 
-    // Play intro
-    for note in intro.iter() {
-        kernel_beep(note.frequency, note.duration_ms)?;
-        // Small break between notes
-        sleep(Duration::from_millis(5));
-    }
-    
-    // Play main theme
-    for note in main_theme.iter() {
-        kernel_beep(note.frequency, note.duration_ms)?;
-        // Small break between notes
-        sleep(Duration::from_millis(5));
-    }
+    let mut notes = Vec::from(intro);
+    notes.extend(main_theme);
+    player.play(notes, 5);
 
     Ok(())
 }
 
 /// Play Happy Birthday song
-fn play_happy_birthday() -> io::Result<()> {
+fn play_happy_birthday(player: &player::Player) -> io::Result<()> {
     println!("Playing Happy Birthday...");
 
     let notes = [
@@ -359,17 +331,13 @@ fn play_happy_birthday() -> io::Result<()> {
         Note::new(NOTE_F4, HALF),
     ];
 
-    for note in notes.iter() {
-        kernel_beep(note.frequency, note.duration_ms)?;
-        // Small break between notes
-        sleep(Duration::from_millis(20));
-    }
+    player.play(Vec::from(notes), 20);
 
     Ok(())
 }
 
 /// Play a custom song from a file
-fn play_custom_song() -> io::Result<()> {
+fn play_custom_song(player: &player::Player) -> io::Result<()> {
     print!("Enter the path to your melody file: ");
     io::stdout().flush()?;
     
@@ -384,111 +352,174 @@ fn play_custom_song() -> io::Result<()> {
     
     println!("Loading melody from: {}", path);
     
-    // Create a note name to frequency mapping
+    // Create a note name to frequency mapping, generated programmatically
+    // for every octave in the MIDI range (0-8) so melody files aren't
+    // limited to the octaves that happen to have hand-written constants.
     let mut note_map: HashMap<String, u32> = HashMap::new();
-    note_map.insert("C3".to_string(), NOTE_C3);
-    note_map.insert("CS3".to_string(), NOTE_CS3);
-    note_map.insert("D3".to_string(), NOTE_D3);
-    note_map.insert("DS3".to_string(), NOTE_DS3);
-    note_map.insert("E3".to_string(), NOTE_E3);
-    note_map.insert("F3".to_string(), NOTE_F3);
-    note_map.insert("FS3".to_string(), NOTE_FS3);
-    note_map.insert("G3".to_string(), NOTE_G3);
-    note_map.insert("GS3".to_string(), NOTE_GS3);
-    note_map.insert("A3".to_string(), NOTE_A3);
-    note_map.insert("AS3".to_string(), NOTE_AS3);
-    note_map.insert("B3".to_string(), NOTE_B3);
-    
-    note_map.insert("C4".to_string(), NOTE_C4);
-    note_map.insert("CS4".to_string(), NOTE_CS4);
-    note_map.insert("D4".to_string(), NOTE_D4);
-    note_map.insert("DS4".to_string(), NOTE_DS4);
-    note_map.insert("E4".to_string(), NOTE_E4);
-    note_map.insert("F4".to_string(), NOTE_F4);
-    note_map.insert("FS4".to_string(), NOTE_FS4);
-    note_map.insert("G4".to_string(), NOTE_G4);
-    note_map.insert("GS4".to_string(), NOTE_GS4);
-    note_map.insert("A4".to_string(), NOTE_A4);
-    note_map.insert("AS4".to_string(), NOTE_AS4);
-    note_map.insert("B4".to_string(), NOTE_B4);
-    
-    note_map.insert("C5".to_string(), NOTE_C5);
-    note_map.insert("CS5".to_string(), NOTE_CS5);
-    note_map.insert("D5".to_string(), NOTE_D5);
-    note_map.insert("DS5".to_string(), NOTE_DS5);
-    note_map.insert("E5".to_string(), NOTE_E5);
-    note_map.insert("F5".to_string(), NOTE_F5);
-    note_map.insert("FS5".to_string(), NOTE_FS5);
-    note_map.insert("G5".to_string(), NOTE_G5);
-    note_map.insert("GS5".to_string(), NOTE_GS5);
-    note_map.insert("A5".to_string(), NOTE_A5);
-    note_map.insert("AS5".to_string(), NOTE_AS5);
-    note_map.insert("B5".to_string(), NOTE_B5);
+    for octave in 0..=8 {
+        for (semitone, name) in SEMITONE_NAMES.iter().enumerate() {
+            note_map.insert(
+                format!("{}{}", name, octave),
+                note_to_freq(semitone as i32, octave),
+            );
+        }
+    }
     note_map.insert("REST".to_string(), NOTE_REST);
-    
-    // Create a duration mapping for text notation
-    let mut duration_map: HashMap<String, u32> = HashMap::new();
-    duration_map.insert("W".to_string(), WHOLE);
-    duration_map.insert("H".to_string(), HALF);
-    duration_map.insert("Q".to_string(), QUARTER);
-    duration_map.insert("E".to_string(), EIGHTH);
-    duration_map.insert("S".to_string(), SIXTEENTH);
-    
+
     // Parse the file
     let file = File::open(path)?;
     let reader = BufReader::new(file);
-    let mut notes = Vec::new();
-    
-    println!("Playing custom melody...");
-    
-    // Parse each line
+    let mut lines: Vec<String> = Vec::new();
     for line in reader.lines() {
         let line = line?;
-        let line = line.trim();
-        
-        // Skip empty lines and comments
+        let line = line.trim().to_string();
         if line.is_empty() || line.starts_with('#') {
             continue;
         }
-        
-        // Split line into parts (note name and duration)
+        lines.push(line);
+    }
+
+    // Optional header lines set the tempo and chord strum speed for the
+    // whole file, e.g. `tempo: allegro`, `bpm: 120` or `strum: 40`.
+    // Defaults to a moderato 100 bpm and the default strum speed.
+    let mut tempo_bpm = tempo::named_tempo("moderato").unwrap();
+    let mut strum_ms = chord::DEFAULT_STRUM_MS;
+    while let Some(first) = lines.first() {
+        let Some((key, value)) = first.split_once(':') else {
+            break;
+        };
+        let key = key.trim().to_ascii_lowercase();
+        let value = value.trim();
+        let recognized = match key.as_str() {
+            "tempo" => match tempo::named_tempo(value) {
+                Some(bpm) => {
+                    tempo_bpm = bpm;
+                    true
+                }
+                None => {
+                    println!("Warning: Unknown tempo marking: {}", value);
+                    true
+                }
+            },
+            "bpm" => match value.parse::<u32>() {
+                Ok(bpm) => {
+                    tempo_bpm = bpm;
+                    true
+                }
+                Err(_) => {
+                    println!("Warning: Invalid bpm value: {}", value);
+                    true
+                }
+            },
+            "strum" => match value.parse::<u32>() {
+                Ok(ms) if ms <= chord::MAX_STRUM_MS => {
+                    strum_ms = ms;
+                    true
+                }
+                Ok(_) => {
+                    println!("Warning: Strum value too large (max {} ms): {}", chord::MAX_STRUM_MS, value);
+                    true
+                }
+                Err(_) => {
+                    println!("Warning: Invalid strum value: {}", value);
+                    true
+                }
+            },
+            _ => false,
+        };
+        if !recognized {
+            break;
+        }
+        lines.remove(0);
+    }
+    let tempo = tempo::Tempo::new(tempo_bpm);
+
+    // Create a duration mapping for text notation, resolved against the tempo
+    let mut duration_map: HashMap<String, u32> = HashMap::new();
+    duration_map.insert("W".to_string(), tempo.whole_ms());
+    duration_map.insert("H".to_string(), tempo.half_ms());
+    duration_map.insert("Q".to_string(), tempo.quarter_ms());
+    duration_map.insert("E".to_string(), tempo.eighth_ms());
+    duration_map.insert("S".to_string(), tempo.sixteenth_ms());
+
+    let mut notes = Vec::new();
+
+    println!("Playing custom melody...");
+
+    // Resolve a note name (or direct frequency) using the note map.
+    let resolve_note = |name: &str| -> Option<u32> {
+        let name = name.to_uppercase();
+        note_map.get(&name).copied().or_else(|| name.parse::<u32>().ok())
+    };
+    // Resolve a duration token (or direct milliseconds) using the duration map.
+    let resolve_duration = |token: &str| -> Option<u32> {
+        duration_map
+            .get(&token.to_uppercase())
+            .copied()
+            .or_else(|| token.parse::<u32>().ok())
+    };
+
+    // Parse each remaining line
+    for line in lines.iter() {
+        let line = line.as_str();
+
+        // A chord line looks like `[C4 E4 G4] Q`: several notes in
+        // brackets, arpeggiated since the hardware is monophonic.
+        if let Some(rest) = line.strip_prefix('[') {
+            let Some(close) = rest.find(']') else {
+                println!("Warning: Unterminated chord: {}", line);
+                continue;
+            };
+            let chord_notes = &rest[..close];
+            let duration_part = rest[close + 1..].trim();
+
+            let Some(duration_ms) = resolve_duration(duration_part) else {
+                println!("Warning: Unknown duration: {}", duration_part);
+                continue;
+            };
+
+            let mut frequencies = Vec::new();
+            for name in chord_notes.split_whitespace() {
+                match resolve_note(name) {
+                    Some(0) => {} // rests inside a chord are dropped
+                    Some(freq) => frequencies.push(freq),
+                    None => println!("Warning: Unknown note name in chord: {}", name),
+                }
+            }
+
+            if frequencies.is_empty() {
+                println!("Warning: Chord has no sounding notes: {}", line);
+                continue;
+            }
+
+            let chord = chord::Chord {
+                notes: frequencies,
+                duration_ms,
+            };
+            notes.extend(chord::arpeggiate(chord, strum_ms));
+            continue;
+        }
+
+        // Otherwise it's a plain `NOTE DURATION` line.
         let parts: Vec<&str> = line.split_whitespace().collect();
         if parts.len() != 2 {
             println!("Warning: Invalid line format: {}", line);
             continue;
         }
-        
-        let note_name = parts[0].to_uppercase();
+
+        let note_name = parts[0];
         let duration_part = parts[1];
-        
-        // Get note frequency
-        let frequency = if let Some(&freq) = note_map.get(&note_name) {
-            freq
-        } else {
-            // Try to parse as direct frequency
-            match note_name.parse::<u32>() {
-                Ok(freq) => freq,
-                Err(_) => {
-                    println!("Warning: Unknown note name: {}", note_name);
-                    continue;
-                }
-            }
+
+        let Some(frequency) = resolve_note(note_name) else {
+            println!("Warning: Unknown note name: {}", note_name);
+            continue;
         };
-        
-        // Get duration
-        let duration = if let Some(&dur) = duration_map.get(&duration_part.to_uppercase()) {
-            dur
-        } else {
-            // Try to parse as direct milliseconds
-            match duration_part.parse::<u32>() {
-                Ok(dur) => dur,
-                Err(_) => {
-                    println!("Warning: Unknown duration: {}", duration_part);
-                    continue;
-                }
-            }
+
+        let Some(duration) = resolve_duration(duration_part) else {
+            println!("Warning: Unknown duration: {}", duration_part);
+            continue;
         };
-        
+
         // Add to notes array
         notes.push(Note::new(frequency, duration));
     }
@@ -497,17 +528,93 @@ fn play_custom_song() -> io::Result<()> {
         println!("No valid notes found in the file.");
         return Ok(());
     }
-    
-    // Play the notes
-    for note in notes.iter() {
-        kernel_beep(note.frequency, note.duration_ms)?;
-        // Small break between notes
-        sleep(Duration::from_millis(10));
+
+    player.play(notes, 10);
+
+    Ok(())
+}
+
+/// Play an RTTTL ringtone string (the classic Nokia-era format)
+fn play_rtttl_ringtone(player: &player::Player) -> io::Result<()> {
+    print!("Paste the RTTTL ringtone string: ");
+    io::stdout().flush()?;
+
+    let mut ringtone = String::new();
+    io::stdin().read_line(&mut ringtone)?;
+    let ringtone = ringtone.trim();
+
+    if ringtone.is_empty() {
+        println!("No ringtone provided.");
+        return Ok(());
     }
-    
+
+    let notes = rtttl::parse_rtttl(ringtone)?;
+
+    if notes.is_empty() {
+        println!("No valid notes found in the ringtone.");
+        return Ok(());
+    }
+
+    println!("Playing RTTTL ringtone...");
+    player.play(notes, 10);
+
+    Ok(())
+}
+
+/// Play a melody described in ABC notation, loaded from a file
+fn play_abc_tune(player: &player::Player) -> io::Result<()> {
+    print!("Enter the path to your ABC tune file: ");
+    io::stdout().flush()?;
+
+    let mut path = String::new();
+    io::stdin().read_line(&mut path)?;
+    let path = path.trim();
+
+    if !Path::new(path).exists() {
+        println!("File not found: {}", path);
+        return Ok(());
+    }
+
+    println!("Loading ABC tune from: {}", path);
+    let contents = fs::read_to_string(path)?;
+    let notes = abc::parse_abc(&contents)?;
+
+    if notes.is_empty() {
+        println!("No valid notes found in the tune.");
+        return Ok(());
+    }
+
+    println!("Playing ABC tune...");
+    player.play(notes, 10);
+
     Ok(())
 }
 
+/// Build the queue of built-in songs used by the Jukebox menu entry.
+fn jukebox_songs() -> Vec<(&'static str, fn(&player::Player) -> io::Result<()>)> {
+    vec![
+        ("Tetris Theme", play_tetris_theme),
+        ("Jingle Bells", play_jingle_bells),
+        ("Imperial March (Star Wars)", play_imperial_march),
+        ("Nokia Tune", play_nokia_tune),
+        ("Super Mario Bros Theme", play_super_mario),
+        ("Happy Birthday", play_happy_birthday),
+    ]
+}
+
+/// Play the built-in songs back-to-back in shuffled order, reshuffling
+/// after each full cycle, until the requested number of cycles is done.
+fn play_jukebox(player: &player::Player) -> io::Result<()> {
+    print!("How many cycles should the jukebox play (default 1)? ");
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    let cycles: u32 = input.trim().parse().unwrap_or(1).max(1);
+
+    playlist::play_jukebox(&jukebox_songs(), cycles, player)
+}
+
 fn main() -> io::Result<()> {
     // Check if running as root
     if !Uid::effective().is_root() {
@@ -523,6 +630,10 @@ fn main() -> io::Result<()> {
     })
     .expect("Error setting Ctrl-C handler");
 
+    // The player runs in the background so picking a song never blocks
+    // the menu - the user can stop, pause or switch tracks mid-playback.
+    let player = player::Player::spawn();
+
     // Main loop
     loop {
         println!("\nBeep Song Player (Rust Edition)");
@@ -533,23 +644,45 @@ fn main() -> io::Result<()> {
         println!("5. Super Mario Bros Theme");
         println!("6. Happy Birthday");
         println!("7. Play Custom Melody");
+        println!("8. Play RTTTL Ringtone");
+        println!("9. Jukebox (Shuffled Playlist)");
+        println!("10. Play ABC Notation Tune");
+        println!("p. Pause playback    r. Resume playback    x. Stop playback");
+        println!("t. Set playback speed");
         println!("q. Quit");
-        
-        print!("Select a song (1-7, q to quit): ");
+
+        print!("Select a song (1-10, q to quit): ");
         io::stdout().flush()?;
-        
+
         let mut choice = String::new();
         io::stdin().read_line(&mut choice)?;
-        
+
         match choice.trim() {
-            "1" => play_tetris_theme()?,
-            "2" => play_jingle_bells()?,
-            "3" => play_imperial_march()?,
-            "4" => play_nokia_tune()?,
-            "5" => play_super_mario()?,
-            "6" => play_happy_birthday()?,
-            "7" => play_custom_song()?,
+            "1" => { player.stop(); play_tetris_theme(&player)?; }
+            "2" => { player.stop(); play_jingle_bells(&player)?; }
+            "3" => { player.stop(); play_imperial_march(&player)?; }
+            "4" => { player.stop(); play_nokia_tune(&player)?; }
+            "5" => { player.stop(); play_super_mario(&player)?; }
+            "6" => { player.stop(); play_happy_birthday(&player)?; }
+            "7" => { player.stop(); play_custom_song(&player)?; }
+            "8" => { player.stop(); play_rtttl_ringtone(&player)?; }
+            "9" => { player.stop(); play_jukebox(&player)?; }
+            "10" => { player.stop(); play_abc_tune(&player)?; }
+            "p" | "P" => player.pause(),
+            "r" | "R" => player.resume(),
+            "x" | "X" => player.stop(),
+            "t" | "T" => {
+                print!("Enter playback speed percent (100 = normal): ");
+                io::stdout().flush()?;
+                let mut speed = String::new();
+                io::stdin().read_line(&mut speed)?;
+                match speed.trim().parse::<f32>() {
+                    Ok(percent) if percent > 0.0 => player.set_tempo(100.0 / percent),
+                    _ => println!("Invalid speed percentage."),
+                }
+            }
             "q" | "Q" => {
+                player.stop();
                 println!("Goodbye!");
                 break;
             }
@@ -558,4 +691,4 @@ fn main() -> io::Result<()> {
     }
 
     Ok(())
-} 
\ No newline at end of file
+}
\ No newline at end of file
@@ -0,0 +1,168 @@
+//! A background playback engine so the menu loop never blocks on a
+//! whole song. The main thread sends [`PlayerCommand`]s over an `mpsc`
+//! channel to a dedicated player thread, which owns the `/dev/console`
+//! handle and steps through notes on its own, checking for `Stop`/
+//! `Pause` between them. A `Play` command that arrives while another is
+//! already sounding is queued rather than discarded, so callers that
+//! queue several songs back-to-back (like the jukebox) get all of them
+//! played in order. A `Stop` ends the current song and drops the queue.
+
+use std::collections::VecDeque;
+use std::fs::{File, OpenOptions};
+use std::io;
+use std::sync::mpsc::{self, Sender};
+use std::thread::{self, sleep, JoinHandle};
+use std::time::Duration;
+
+use crate::Note;
+
+/// A command sent to the player thread.
+pub(crate) enum PlayerCommand {
+    /// Play this sequence of notes, leaving `gap_ms` of silence between
+    /// each one for clarity. If `done` is set, it's signalled once the
+    /// song finishes (naturally or via `Stop`).
+    Play {
+        notes: Vec<Note>,
+        gap_ms: u32,
+        done: Option<Sender<()>>,
+    },
+    Stop,
+    Pause,
+    Resume,
+    /// Scale every subsequent note's duration by this factor (e.g. 0.5
+    /// plays at double speed).
+    SetTempo(f32),
+}
+
+/// Handle to the background player thread.
+pub(crate) struct Player {
+    tx: Sender<PlayerCommand>,
+    _handle: JoinHandle<()>,
+}
+
+impl Player {
+    /// Spawn the player thread, opening `/dev/console` once up front.
+    pub(crate) fn spawn() -> Self {
+        let (tx, rx) = mpsc::channel::<PlayerCommand>();
+
+        let console = match OpenOptions::new().read(true).write(true).open("/dev/console") {
+            Ok(console) => Some(console),
+            Err(err) => {
+                eprintln!("Could not open console: {}", err);
+                None
+            }
+        };
+
+        let handle = thread::spawn(move || {
+            let mut tempo_factor = 1.0f32;
+            // Play commands popped by try_recv/recv below while a song is
+            // already in flight (e.g. the jukebox queueing its next track)
+            // wait here instead of being discarded, and are played in the
+            // order they arrived once the current song finishes.
+            let mut queued_songs: VecDeque<PlayerCommand> = VecDeque::new();
+
+            loop {
+                let command = match queued_songs.pop_front() {
+                    Some(command) => command,
+                    None => match rx.recv() {
+                        Ok(command) => command,
+                        Err(_) => break,
+                    },
+                };
+
+                match command {
+                    PlayerCommand::Play { notes, gap_ms, done } => {
+                        'notes: for note in notes {
+                            let duration_ms = ((note.duration_ms as f32) * tempo_factor) as u32;
+                            let _ = beep(console.as_ref(), note.frequency, duration_ms);
+
+                            if gap_ms > 0 {
+                                sleep(Duration::from_millis(gap_ms as u64));
+                            }
+
+                            // Check for Stop/Pause between notes without blocking.
+                            match rx.try_recv() {
+                                Ok(PlayerCommand::Stop) => {
+                                    queued_songs.clear();
+                                    break;
+                                }
+                                Err(mpsc::TryRecvError::Disconnected) => break,
+                                Ok(PlayerCommand::Pause) => {
+                                    // Block until told to resume or stop.
+                                    loop {
+                                        match rx.recv() {
+                                            Ok(PlayerCommand::Resume) | Err(_) => break,
+                                            Ok(PlayerCommand::Stop) => {
+                                                queued_songs.clear();
+                                                break 'notes;
+                                            }
+                                            Ok(PlayerCommand::SetTempo(factor)) => {
+                                                tempo_factor = factor;
+                                            }
+                                            Ok(play @ PlayerCommand::Play { .. }) => {
+                                                queued_songs.push_back(play);
+                                            }
+                                            Ok(_) => {}
+                                        }
+                                    }
+                                }
+                                Ok(PlayerCommand::SetTempo(factor)) => tempo_factor = factor,
+                                Ok(play @ PlayerCommand::Play { .. }) => queued_songs.push_back(play),
+                                Ok(_) | Err(mpsc::TryRecvError::Empty) => {}
+                            }
+                        }
+
+                        if let Some(done) = done {
+                            let _ = done.send(());
+                        }
+                    }
+                    PlayerCommand::SetTempo(factor) => tempo_factor = factor,
+                    PlayerCommand::Stop | PlayerCommand::Pause | PlayerCommand::Resume => {}
+                }
+            }
+        });
+
+        Self {
+            tx,
+            _handle: handle,
+        }
+    }
+
+    /// Queue a song for playback, returning immediately.
+    pub(crate) fn play(&self, notes: Vec<Note>, gap_ms: u32) {
+        let _ = self.tx.send(PlayerCommand::Play {
+            notes,
+            gap_ms,
+            done: None,
+        });
+    }
+
+    /// Stop whatever is currently playing and drop any songs queued
+    /// behind it.
+    pub(crate) fn stop(&self) {
+        let _ = self.tx.send(PlayerCommand::Stop);
+    }
+
+    /// Pause playback after the note currently sounding finishes.
+    pub(crate) fn pause(&self) {
+        let _ = self.tx.send(PlayerCommand::Pause);
+    }
+
+    /// Resume playback after a pause.
+    pub(crate) fn resume(&self) {
+        let _ = self.tx.send(PlayerCommand::Resume);
+    }
+
+    /// Scale the duration of every subsequent note by `factor`.
+    pub(crate) fn set_tempo(&self, factor: f32) {
+        let _ = self.tx.send(PlayerCommand::SetTempo(factor));
+    }
+}
+
+/// Trigger a single beep on an already-open console handle, falling
+/// back to the terminal bell if there is none or the ioctl call fails.
+fn beep(console: Option<&File>, frequency: u32, duration_ms: u32) -> io::Result<()> {
+    crate::beep_on_console(console, frequency, duration_ms)?;
+    sleep(Duration::from_millis(duration_ms as u64));
+    Ok(())
+}
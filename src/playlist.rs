@@ -0,0 +1,79 @@
+//! A shuffled, repeatable playlist ("Jukebox" mode) over the built-in
+//! songs.
+
+use std::io;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::player::Player;
+
+/// A tiny xorshift PRNG so the jukebox can shuffle without pulling in
+/// an external crate.
+struct SimpleRng {
+    state: u64,
+}
+
+impl SimpleRng {
+    fn new() -> Self {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0x2545F4914F6CDD1D);
+        Self {
+            state: seed | 1, // xorshift requires a non-zero state
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+
+    /// Random index in `0..bound` (bound must be > 0).
+    fn gen_range(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
+
+/// Fisher-Yates shuffle of `0..len`.
+fn shuffled_indices(len: usize, rng: &mut SimpleRng) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..len).collect();
+    for i in (1..len).rev() {
+        let j = rng.gen_range(i + 1);
+        indices.swap(i, j);
+    }
+    indices
+}
+
+/// Queue every entry in `songs` back-to-back in shuffled order,
+/// reshuffling after each full cycle, for `cycles` cycles. Every song's
+/// notes are queued on `player` one after another without waiting for
+/// playback to actually finish, so the whole playlist becomes one
+/// continuous background sequence and the menu loop stays free to
+/// pause, stop or retime it while it plays.
+pub(crate) fn play_jukebox(
+    songs: &[(&str, fn(&Player) -> io::Result<()>)],
+    cycles: u32,
+    player: &Player,
+) -> io::Result<()> {
+    if songs.is_empty() {
+        return Ok(());
+    }
+
+    let mut rng = SimpleRng::new();
+
+    for cycle in 1..=cycles {
+        println!("\nJukebox: cycle {} of {}", cycle, cycles);
+        let order = shuffled_indices(songs.len(), &mut rng);
+        for index in order {
+            let (name, play) = songs[index];
+            println!("Queued: {}", name);
+            play(player)?;
+        }
+    }
+
+    Ok(())
+}
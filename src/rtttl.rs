@@ -0,0 +1,149 @@
+//! Parser for RTTTL (Ring Tone Text Transfer Language), the classic
+//! Nokia-era ringtone format: `name:defaults:notes`.
+
+use std::io;
+
+use crate::Note;
+
+/// Parse an RTTTL string into a sequence of playable [`Note`]s.
+///
+/// Malformed note tokens are skipped with a warning printed to stdout,
+/// mirroring the behaviour of the custom melody loader.
+pub fn parse_rtttl(data: &str) -> io::Result<Vec<Note>> {
+    let data: String = data.chars().filter(|c| !c.is_whitespace()).collect();
+
+    let mut sections = data.splitn(3, ':');
+    let _name = sections.next().unwrap_or_default();
+    let defaults = sections.next().unwrap_or_default();
+    let note_list = match sections.next() {
+        Some(notes) => notes,
+        None => {
+            println!("Warning: RTTTL string is missing its note section");
+            return Ok(Vec::new());
+        }
+    };
+
+    // Defaults: d=<duration>,o=<octave>,b=<bpm>
+    let mut default_duration: u32 = 4;
+    let mut default_octave: u32 = 6;
+    let mut bpm: u32 = 63;
+
+    for pair in defaults.split(',') {
+        let pair = pair.trim();
+        if pair.is_empty() {
+            continue;
+        }
+        let mut kv = pair.splitn(2, '=');
+        let key = kv.next().unwrap_or_default().to_ascii_lowercase();
+        let value = kv.next().unwrap_or_default();
+        match (key.as_str(), value.parse::<u32>()) {
+            ("d", Ok(v)) => default_duration = v,
+            ("o", Ok(v)) => default_octave = v,
+            ("b", Ok(v)) => bpm = v,
+            (_, Err(_)) => println!("Warning: Invalid RTTTL default: {}", pair),
+            _ => println!("Warning: Unknown RTTTL default key: {}", pair),
+        }
+    }
+
+    let whole_note_ms = (60_000 / bpm.max(1)) * 4;
+    let mut notes = Vec::new();
+
+    for token in note_list.split(',') {
+        if token.is_empty() {
+            continue;
+        }
+        match parse_note_token(token, default_duration, default_octave, whole_note_ms) {
+            Some(note) => notes.push(note),
+            None => println!("Warning: Invalid RTTTL note token: {}", token),
+        }
+    }
+
+    Ok(notes)
+}
+
+/// Parse a single `[duration][note][#][.][octave]` token.
+fn parse_note_token(
+    token: &str,
+    default_duration: u32,
+    default_octave: u32,
+    whole_note_ms: u32,
+) -> Option<Note> {
+    let chars: Vec<char> = token.to_ascii_lowercase().chars().collect();
+    let mut i = 0;
+
+    // Optional leading duration digits.
+    let digit_start = i;
+    while i < chars.len() && chars[i].is_ascii_digit() {
+        i += 1;
+    }
+    let duration_value: u32 = if i > digit_start {
+        chars[digit_start..i].iter().collect::<String>().parse().ok()?
+    } else {
+        default_duration
+    };
+
+    // Note letter: 'p' for a rest, or 'a'-'g'.
+    let letter = *chars.get(i)?;
+    i += 1;
+    let semitone = match letter {
+        'p' => None,
+        'a'..='g' => Some(letter_to_semitone(letter)),
+        _ => return None,
+    };
+
+    // Optional sharp.
+    let mut sharp = false;
+    if chars.get(i) == Some(&'#') {
+        sharp = true;
+        i += 1;
+    }
+
+    // Optional dotted marker.
+    let mut dotted = false;
+    if chars.get(i) == Some(&'.') {
+        dotted = true;
+        i += 1;
+    }
+
+    // Optional trailing octave digit(s).
+    let octave_start = i;
+    while i < chars.len() && chars[i].is_ascii_digit() {
+        i += 1;
+    }
+    let octave: u32 = if i > octave_start {
+        chars[octave_start..i].iter().collect::<String>().parse().ok()?
+    } else {
+        default_octave
+    };
+
+    // Reject absurd octaves rather than overflowing note_to_freq's MIDI
+    // math - RTTTL only ever uses single-digit octaves in practice.
+    if i != chars.len() || duration_value == 0 || octave > 10 {
+        return None;
+    }
+
+    let mut duration_ms = whole_note_ms / duration_value;
+    if dotted {
+        duration_ms = duration_ms * 3 / 2;
+    }
+
+    let frequency = match semitone {
+        Some(semitone) => crate::note_to_freq(semitone + if sharp { 1 } else { 0 }, octave as i32),
+        None => 0,
+    };
+
+    Some(Note::new(frequency, duration_ms))
+}
+
+fn letter_to_semitone(letter: char) -> i32 {
+    match letter {
+        'c' => 0,
+        'd' => 2,
+        'e' => 4,
+        'f' => 5,
+        'g' => 7,
+        'a' => 9,
+        'b' => 11,
+        _ => unreachable!("caller only passes a-g"),
+    }
+}
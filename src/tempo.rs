@@ -0,0 +1,61 @@
+//! Beat-based timing for custom melody files: a tempo in beats per
+//! minute, plus the traditional Italian tempo markings used by real
+//! scores instead of a bare BPM number.
+
+/// Traditional tempo markings mapped to their beats-per-minute value,
+/// ordered from slowest to fastest.
+pub(crate) const TEMPO_NAMES: [(&str, u32); 12] = [
+    ("grave", 40),
+    ("largo", 46),
+    ("lento", 52),
+    ("adagio", 56),
+    ("larghetto", 60),
+    ("andante", 72),
+    ("moderato", 100),
+    ("allegretto", 104),
+    ("allegro", 132),
+    ("vivace", 160),
+    ("presto", 184),
+    ("prestissimo", 208),
+];
+
+/// Look up a named tempo marking, case-insensitively.
+pub(crate) fn named_tempo(name: &str) -> Option<u32> {
+    let name = name.to_ascii_lowercase();
+    TEMPO_NAMES
+        .iter()
+        .find(|(marking, _)| *marking == name)
+        .map(|(_, bpm)| *bpm)
+}
+
+/// A tempo in beats per minute, where a beat is a quarter note.
+pub(crate) struct Tempo {
+    bpm: u32,
+}
+
+impl Tempo {
+    pub(crate) fn new(bpm: u32) -> Self {
+        Self { bpm: bpm.max(1) }
+    }
+
+    /// Duration in ms of a quarter note at this tempo.
+    pub(crate) fn quarter_ms(&self) -> u32 {
+        60_000 / self.bpm
+    }
+
+    pub(crate) fn whole_ms(&self) -> u32 {
+        self.quarter_ms() * 4
+    }
+
+    pub(crate) fn half_ms(&self) -> u32 {
+        self.quarter_ms() * 2
+    }
+
+    pub(crate) fn eighth_ms(&self) -> u32 {
+        self.quarter_ms() / 2
+    }
+
+    pub(crate) fn sixteenth_ms(&self) -> u32 {
+        self.quarter_ms() / 4
+    }
+}